@@ -1,6 +1,6 @@
 use std::io::prelude::*;
-use std::io::ErrorKind::Interrupted;
-use std::io::Result;
+use std::io::ErrorKind::{Interrupted, UnexpectedEof};
+use std::io::{Error, IoSliceMut, Result};
 
 /// Adds the [`fill`](Fill::fill) method to Read implementors.
 pub trait Fill: Read {
@@ -31,6 +31,68 @@ pub trait Fill: Read {
                 Ok(l) => bytes_read += l,
             };
     }}
+
+    /// Fill the given slices, in order, as if they were one contiguous buffer. This will call
+    /// [`read_vectored`](std::io::Read::read_vectored) on `self` until it returns `0` or an
+    /// error which is not [`ErrorKind::Interrupted`](std::io::ErrorKind::Interrupted), indicating
+    /// that there is no more data available currently, or every slice in `bufs` is full. Returns
+    /// the total number of bytes read across all slices. See also [`fill`](Fill::fill), which
+    /// operates similarly for a single buffer.
+    ///
+    /// ```
+    /// # use std::io::{Cursor, Error, IoSliceMut};
+    /// use fill::Fill;
+    /// let mut cursor = Cursor::new("Hello, World!");
+    /// let mut head = [0_u8; 5];
+    /// let mut tail = [0_u8; 8];
+    /// let mut bufs = [IoSliceMut::new(&mut head), IoSliceMut::new(&mut tail)];
+    /// assert_eq!(cursor.fill_vectored(&mut bufs)?, 13);
+    /// assert_eq!(&head, b"Hello");
+    /// assert_eq!(&tail, b", World!");
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn fill_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
+        let mut bufs = bufs;
+        let mut total_read: usize = 0;
+        while !bufs.is_empty() {
+            match self.read_vectored(bufs) {
+                Err(e) => match e.kind() {
+                    Interrupted => continue,
+                    _ => return Err(e),
+                },
+                Ok(0) => return Ok(total_read),
+                Ok(l) => {
+                    total_read += l;
+                    IoSliceMut::advance_slices(&mut bufs, l);
+                }
+            }
+        }
+        Ok(total_read)
+    }
+
+    /// Fill the given buffer exactly, unlike [`fill`](Fill::fill) which may return short at EOF.
+    /// This uses the same [`Interrupted`](std::io::ErrorKind::Interrupted)-retrying loop as
+    /// `fill`, but returns an [`ErrorKind::UnexpectedEof`](std::io::ErrorKind::UnexpectedEof)
+    /// error if the stream ends before `buf` is completely filled. On error, the contents of
+    /// `buf` are unspecified (it may have been partially filled). Unlike
+    /// [`read_exact`](std::io::Read::read_exact), transparent retry on interruption is guaranteed.
+    ///
+    /// ```
+    /// # use std::io::{Cursor, Error};
+    /// use fill::Fill;
+    /// let mut cursor = Cursor::new("Hello, World!");
+    /// let mut buf = [0_u8; 13];
+    /// cursor.fill_exact(&mut buf)?;
+    /// assert_eq!(&buf, b"Hello, World!");
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn fill_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        let len = buf.len();
+        match self.fill(buf)? {
+            l if l == len => Ok(()),
+            _ => Err(Error::new(UnexpectedEof, "failed to fill whole buffer")),
+        }
+    }
 }
 
 /// Implement `Fill` for all types that implement [`Read`].
@@ -51,6 +113,54 @@ impl<R: Read> ChunkedReader<R> {
     }
 }
 
+/// A chunk-at-a-time reader which reuses a single internal buffer instead of allocating a fresh
+/// `Vec<u8>` on every chunk, at the cost of borrowing the chunk from `self` rather than owning it.
+/// Because the returned slice borrows from the struct, this cannot implement [`Iterator`]; use
+/// [`next_chunk`](ReusableChunkedReader::next_chunk) or [`for_each`](ReusableChunkedReader::for_each) instead.
+#[must_use = "does nothing unless `next_chunk` or `for_each` is called"]
+pub struct ReusableChunkedReader<R: Read> {
+    read: R,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> ReusableChunkedReader<R> {
+    /// Consumes the [`ReusableChunkedReader`], returning the underlying [`Read`]er.
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+
+    /// Fills the internal buffer via [`fill`](Fill::fill) and returns a borrowed slice of the
+    /// bytes read. Returns `None` once the underlying reader is exhausted, or `Some(Err(e))` if
+    /// a read fails.
+    ///
+    /// ```
+    /// # use std::io::{Cursor, Error};
+    /// use fill::Chunk;
+    /// let mut reader = Cursor::new("Hello, World!").chunked_reuse(5);
+    /// assert_eq!(reader.next_chunk().unwrap()?, b"Hello");
+    /// assert_eq!(reader.next_chunk().unwrap()?, b", Wor");
+    /// assert_eq!(reader.next_chunk().unwrap()?, b"ld!");
+    /// assert!(reader.next_chunk().is_none());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn next_chunk(&mut self) -> Option<Result<&[u8]>> {
+        match self.read.fill(&mut self.buf) {
+            Err(e) => Some(Err(e)),
+            Ok(0) => None,
+            Ok(l) => Some(Ok(&self.buf[..l])),
+        }
+    }
+
+    /// Calls `f` with each chunk in turn until the underlying reader is exhausted or `f` returns
+    /// an error, which is then propagated to the caller.
+    pub fn for_each(&mut self, mut f: impl FnMut(&[u8]) -> Result<()>) -> Result<()> {
+        while let Some(chunk) = self.next_chunk() {
+            f(chunk?)?;
+        }
+        Ok(())
+    }
+}
+
 impl<R: Read> Iterator for ChunkedReader<R> {
     type Item = Result<Vec<u8>>;
 
@@ -70,7 +180,7 @@ impl<R: Read> Iterator for ChunkedReader<R> {
     }
 }
 
-trait Chunk: Read {
+pub trait Chunk: Read {
     /// Consumes `self`, returning a [`ChunkedReader`] over `self`.
     ///
     /// Panics if `size` is `0`.
@@ -81,8 +191,211 @@ trait Chunk: Read {
         assert!(size != 0);
         ChunkedReader { read: self, size }
     }
+
+    /// Consumes `self`, returning a [`ReusableChunkedReader`] over `self` which reuses a single
+    /// `size`-byte buffer across chunks instead of allocating one per chunk.
+    ///
+    /// Panics if `size` is `0`.
+    fn chunked_reuse(self, size: usize) -> ReusableChunkedReader<Self>
+    where
+        Self: Sized,
+    {
+        assert!(size != 0);
+        ReusableChunkedReader {
+            read: self,
+            buf: vec![0_u8; size],
+        }
+    }
 }
 
 /// Implement `Chunk` for all types that implement [`Read`].
 impl<R: Read> Chunk for R {}
 
+/// An [`Iterator`] which wraps a [`BufRead`]er. Each call to [`next`](DelimitedReader::next)
+/// returns the bytes up to (and excluding) the next occurrence of `delim` as a `Result<Vec<u8>>`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct DelimitedReader<R: BufRead> {
+    read: R,
+    delim: u8,
+    done: bool,
+}
+
+impl<R: BufRead> DelimitedReader<R> {
+    /// Consumes the [`DelimitedReader`], returning the underlying [`BufRead`]er.
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+}
+
+impl<R: BufRead> Iterator for DelimitedReader<R> {
+    type Item = Result<Vec<u8>>;
+
+    /// Scans the underlying reader's fill buffer for `delim`. If found, returns the bytes up to
+    /// it and consumes the delimiter along with them. If not found, the whole buffer is consumed
+    /// and appended, and the scan continues. Returns the final trailing segment (if non-empty)
+    /// before returning `None`, and surfaces any read error as `Some(Err(e))`.
+    fn next(&mut self) -> Option<Result<Vec<u8>>> {
+        if self.done {
+            return None;
+        }
+        let mut chunk = Vec::new();
+        loop {
+            let available = match self.read.fill_buf() {
+                Err(e) => match e.kind() {
+                    Interrupted => continue,
+                    _ => return Some(Err(e)),
+                },
+                Ok(available) => available,
+            };
+            if available.is_empty() {
+                self.done = true;
+                return if chunk.is_empty() {
+                    None
+                } else {
+                    Some(Ok(chunk))
+                };
+            }
+            match available.iter().position(|&b| b == self.delim) {
+                Some(i) => {
+                    chunk.extend_from_slice(&available[..i]);
+                    self.read.consume(i + 1);
+                    return Some(Ok(chunk));
+                }
+                None => {
+                    let len = available.len();
+                    chunk.extend_from_slice(available);
+                    self.read.consume(len);
+                }
+            }
+        }
+    }
+}
+
+pub trait Split: BufRead {
+    /// Consumes `self`, returning a [`DelimitedReader`] which splits the stream on `delim`.
+    ///
+    /// ```
+    /// # use std::io::{BufReader, Cursor, Error};
+    /// use fill::Split;
+    /// // A 1-byte buffer forces multiple `fill_buf` refills per segment, exercising the
+    /// // "delimiter not found in this refill" branch.
+    /// let reader = BufReader::with_capacity(1, Cursor::new("foo,bar,baz"));
+    /// let mut split = reader.split_on(b',');
+    /// assert_eq!(split.next().unwrap()?, b"foo");
+    /// assert_eq!(split.next().unwrap()?, b"bar");
+    /// assert_eq!(split.next().unwrap()?, b"baz");
+    /// assert!(split.next().is_none());
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn split_on(self, delim: u8) -> DelimitedReader<Self>
+    where
+        Self: Sized,
+    {
+        DelimitedReader {
+            read: self,
+            delim,
+            done: false,
+        }
+    }
+}
+
+/// Implement `Split` for all types that implement [`BufRead`].
+impl<R: BufRead> Split for R {}
+
+/// Adds the [`drain_buf`](Drain::drain_buf) method to Write implementors.
+pub trait Drain: Write {
+    /// Drain the given buffer into `self`. This will call `write` on `self` until `write`
+    /// returns `0` or an error which is not [`ErrorKind::Interrupted`](std::io::ErrorKind::Interrupted),
+    /// indicating that no more of `buf` can be accepted currently, or all of `buf` has been
+    /// written. Returns the total number of bytes written, rather than erroring, when `write`
+    /// returns `0` before `buf` is exhausted. See also [`fill`](Fill::fill), the read-side mirror.
+    ///
+    /// Named `drain_buf` rather than `drain` so it doesn't shadow the inherent `drain` method
+    /// found on `Vec`, `VecDeque` and `String`, which would otherwise win dot-call resolution.
+    ///
+    /// ```
+    /// # use std::io::Error;
+    /// use fill::Drain;
+    /// let mut out = Vec::new();
+    /// assert_eq!(out.drain_buf(b"Hello, World!")?, 13);
+    /// assert_eq!(out, b"Hello, World!");
+    /// # Ok::<(), Error>(())
+    /// ```
+    fn drain_buf(&mut self, buf: &[u8]) -> Result<usize> {
+        let mut bytes_written: usize = 0;
+        loop {
+            match self.write(&buf[bytes_written..]) {
+                Err(e) => match e.kind() {
+                    Interrupted => continue,
+                    _ => return Err(e),
+                },
+                Ok(0) => return Ok(bytes_written),
+                Ok(l) => bytes_written += l,
+            }
+            if bytes_written == buf.len() {
+                return Ok(bytes_written);
+            }
+        }
+    }
+}
+
+/// Implement `Drain` for all types that implement [`Write`].
+impl<W: Write> Drain for W {}
+
+/// A [`Write`]r adaptor which splits oversized writes into `self.size`-capped
+/// [`drain_buf`](Drain::drain_buf) calls.
+pub struct ChunkedWriter<W: Write> {
+    write: W,
+    size: usize,
+}
+
+impl<W: Write> ChunkedWriter<W> {
+    /// Consumes the [`ChunkedWriter`], returning the underlying [`Write`]r.
+    pub fn into_inner(self) -> W {
+        self.write
+    }
+
+    /// Writes `buf` to the underlying writer in `self.size`-capped chunks, draining each chunk
+    /// fully before moving on to the next. Returns the total number of bytes written.
+    ///
+    /// ```
+    /// # use std::io::Error;
+    /// use fill::ChunkWrite;
+    /// let mut writer = Vec::new().chunked_writer(4);
+    /// assert_eq!(writer.write_chunk(b"Hello, World!")?, 13);
+    /// assert_eq!(writer.into_inner(), b"Hello, World!");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn write_chunk(&mut self, buf: &[u8]) -> Result<usize> {
+        let mut bytes_written: usize = 0;
+        for chunk in buf.chunks(self.size) {
+            let l = self.write.drain_buf(chunk)?;
+            bytes_written += l;
+            if l != chunk.len() {
+                break;
+            }
+        }
+        Ok(bytes_written)
+    }
+}
+
+pub trait ChunkWrite: Write {
+    /// Consumes `self`, returning a [`ChunkedWriter`] over `self`.
+    ///
+    /// Named `chunked_writer` rather than `chunked` so it doesn't collide with
+    /// [`Chunk::chunked`], which would otherwise make `.chunked(n)` ambiguous for any type that
+    /// implements both `Read` and `Write` (e.g. `Cursor<Vec<u8>>`, `File`, `TcpStream`).
+    ///
+    /// Panics if `size` is `0`.
+    fn chunked_writer(self, size: usize) -> ChunkedWriter<Self>
+    where
+        Self: Sized,
+    {
+        assert!(size != 0);
+        ChunkedWriter { write: self, size }
+    }
+}
+
+/// Implement `ChunkWrite` for all types that implement [`Write`].
+impl<W: Write> ChunkWrite for W {}
+